@@ -5,11 +5,18 @@
 pub mod shiden34 {
     // imports from ink!
     use ink_lang::codegen::Env;
-    use ink_prelude::string::{
-        String,
-        ToString,
+    use ink_prelude::{
+        string::{
+            String,
+            ToString,
+        },
+        vec::Vec,
+    };
+    use ink_storage::{
+        traits::SpreadAllocate,
+        Mapping,
     };
-    use ink_storage::traits::SpreadAllocate;
+    use scale::Encode;
 
     // imports from openbrush
     use openbrush::{
@@ -27,6 +34,26 @@ pub mod shiden34 {
         traits::Storage,
     };
 
+    /// Maximum number of creators that can share in the royalties of this collection
+    const MAX_CREATOR_LIMIT: usize = 5;
+
+    /// Edition prints live in their own id namespace so they never compete with
+    /// `last_token_id`/`max_supply` for the base collection
+    const EDITION_ID_OFFSET: u64 = 1 << 48;
+
+    /// How a token's remaining uses are consumed
+    #[derive(scale::Encode, scale::Decode, Debug, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum UseMethod {
+        /// The token is burned once its uses are exhausted
+        Burn,
+        /// The token keeps existing once its uses are exhausted
+        Multiple,
+        /// The token keeps existing once its uses are exhausted; `set_uses` does not
+        /// limit its total to one use
+        Single,
+    }
+
     #[ink(storage)]
     #[derive(Default, SpreadAllocate, Storage)]
     pub struct Shiden34Contract {
@@ -44,6 +71,31 @@ pub mod shiden34 {
         collection_id: u32,
         max_supply: u64,
         price_per_mint: Balance,
+        seller_fee_basis_points: u16,
+        creators: Vec<(AccountId, u16)>,
+        uses: Mapping<Id, (UseMethod, u64, u64)>,
+        collection_authority: Option<AccountId>,
+        verified: Mapping<Id, bool>,
+        masters: Mapping<Id, u64>,
+        editions: Mapping<Id, (Id, u64)>,
+        edition_counters: Mapping<Id, u64>,
+        last_edition_id: u64,
+        merkle_root: [u8; 32],
+        presale_active: bool,
+        presale_price: Option<Balance>,
+        presale_mints: Mapping<AccountId, u64>,
+    }
+
+    #[ink(event)]
+    pub struct CollectionVerified {
+        #[ink(topic)]
+        token_id: Id,
+    }
+
+    #[ink(event)]
+    pub struct CollectionUnverified {
+        #[ink(topic)]
+        token_id: Id,
     }
 
     // Section contains default implementation without any modifications
@@ -63,6 +115,57 @@ pub mod shiden34 {
         fn token_uri(&self, token_id: u64) -> Result<String, PSP34Error>;
         #[ink(message)]
         fn max_supply(&self) -> u64;
+        #[ink(message)]
+        fn set_royalties(
+            &mut self,
+            fee_bps: u16,
+            creators: Vec<(AccountId, u16)>,
+        ) -> Result<(), PSP34Error>;
+        #[ink(message)]
+        fn royalty_info(
+            &self,
+            token_id: u64,
+            sale_price: Balance,
+        ) -> Result<Vec<(AccountId, Balance)>, PSP34Error>;
+        #[ink(message)]
+        fn set_uses(&mut self, token_id: u64, method: UseMethod, total: u64) -> Result<(), PSP34Error>;
+        #[ink(message)]
+        fn use_token(&mut self, token_id: u64) -> Result<(), PSP34Error>;
+        #[ink(message)]
+        fn uses_of(&self, token_id: u64) -> Option<(UseMethod, u64, u64)>;
+        #[ink(message)]
+        fn set_collection_authority(&mut self, authority: AccountId) -> Result<(), PSP34Error>;
+        #[ink(message)]
+        fn verify_collection(&mut self, token_id: u64) -> Result<(), PSP34Error>;
+        #[ink(message)]
+        fn unverify_collection(&mut self, token_id: u64) -> Result<(), PSP34Error>;
+        #[ink(message)]
+        fn is_verified(&self, token_id: u64) -> bool;
+        #[ink(message)]
+        fn create_master_edition(&mut self, token_id: u64, max_editions: u64) -> Result<(), PSP34Error>;
+        #[ink(message, payable)]
+        fn mint_edition_from_master(&mut self, master_id: u64) -> Result<(), PSP34Error>;
+        #[ink(message)]
+        fn edition_of(&self, token_id: u64) -> Option<(u64, u64)>;
+        #[ink(message)]
+        fn withdraw(&mut self) -> Result<(), PSP34Error>;
+        #[ink(message)]
+        fn set_price_per_mint(&mut self, price: Balance) -> Result<(), PSP34Error>;
+        #[ink(message)]
+        fn set_max_supply(&mut self, max_supply: u64) -> Result<(), PSP34Error>;
+        #[ink(message)]
+        fn set_merkle_root(&mut self, root: [u8; 32]) -> Result<(), PSP34Error>;
+        #[ink(message)]
+        fn set_presale_active(&mut self, active: bool) -> Result<(), PSP34Error>;
+        #[ink(message)]
+        fn set_presale_price(&mut self, price: Option<Balance>) -> Result<(), PSP34Error>;
+        #[ink(message, payable)]
+        fn mint_presale(
+            &mut self,
+            proof: Vec<[u8; 32]>,
+            max_allowed: u64,
+            mint_amount: u64,
+        ) -> Result<(), PSP34Error>;
     }
 
     impl Shiden34Contract {
@@ -73,7 +176,11 @@ pub mod shiden34 {
             base_uri: String,
             max_supply: u64,
             price_per_mint: Balance,
+            fee_bps: u16,
+            creators: Vec<(AccountId, u16)>,
+            collection_authority: Option<AccountId>,
         ) -> Self {
+            assert!(Self::check_royalties(fee_bps, &creators).is_ok());
             ink_lang::codegen::initialize_contract(|_instance: &mut Shiden34Contract| {
                 _instance._set_attribute(
                     Id::U8(0),
@@ -93,6 +200,9 @@ pub mod shiden34 {
                 _instance.max_supply = max_supply;
                 _instance.price_per_mint = price_per_mint;
                 _instance.last_token_id = 0;
+                _instance.seller_fee_basis_points = fee_bps;
+                _instance.creators = creators;
+                _instance.collection_authority = collection_authority;
                 let caller = _instance.env().caller();
                 _instance._init_with_owner(caller);
             })
@@ -123,6 +233,67 @@ pub mod shiden34 {
             self.owner_of(id).ok_or(PSP34Error::TokenNotExists)?;
             Ok(())
         }
+
+        /// Check that a royalty configuration is well formed
+        fn check_royalties(fee_bps: u16, creators: &[(AccountId, u16)]) -> Result<(), PSP34Error> {
+            if fee_bps > 10_000 {
+                return Err(PSP34Error::Custom("InvalidFeeBasisPoints".to_string()))
+            }
+
+            if creators.len() > MAX_CREATOR_LIMIT {
+                return Err(PSP34Error::Custom("TooManyCreators".to_string()))
+            }
+
+            if !creators.is_empty() {
+                let total_share: u16 = creators.iter().map(|(_, share)| share).sum();
+                if total_share != 100 {
+                    return Err(PSP34Error::Custom("InvalidCreatorShares".to_string()))
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Check that the caller is the token owner or an approved operator, returning the owner
+        fn ensure_owner_or_approved(&self, id: Id, caller: AccountId) -> Result<AccountId, PSP34Error> {
+            let owner = self.owner_of(id.clone()).ok_or(PSP34Error::TokenNotExists)?;
+            if owner != caller && !self.allowance(owner, caller, Some(id)) {
+                return Err(PSP34Error::NotApproved)
+            }
+            Ok(owner)
+        }
+
+        /// Check that the caller is the collection authority
+        fn ensure_collection_authority(&self) -> Result<(), PSP34Error> {
+            if Some(self.env().caller()) != self.collection_authority {
+                return Err(PSP34Error::Custom("NotCollectionAuthority".to_string()))
+            }
+            Ok(())
+        }
+
+        /// Hash arbitrary bytes with keccak256
+        fn keccak256(data: &[u8]) -> [u8; 32] {
+            let mut output = [0u8; 32];
+            ink_env::hash_bytes::<ink_env::hash::Keccak256>(data, &mut output);
+            output
+        }
+
+        /// Check that `(caller, max_allowed)` is a member of the presale allowlist
+        fn verify_presale_proof(&self, caller: AccountId, max_allowed: u64, proof: Vec<[u8; 32]>) -> bool {
+            let mut leaf_input = caller.encode();
+            leaf_input.extend_from_slice(&max_allowed.to_le_bytes());
+            let mut computed_hash = Self::keccak256(&leaf_input);
+
+            for sibling in proof {
+                computed_hash = if computed_hash <= sibling {
+                    Self::keccak256(&[computed_hash, sibling].concat())
+                } else {
+                    Self::keccak256(&[sibling, computed_hash].concat())
+                };
+            }
+
+            computed_hash == self.merkle_root
+        }
     }
 
     impl PSP34Mintable for Shiden34Contract {
@@ -173,9 +344,13 @@ pub mod shiden34 {
         #[ink(message)]
         fn token_uri(&self, token_id: u64) -> Result<String, PSP34Error> {
             _ = self.token_exists(Id::U64(token_id))?;
+            let metadata_id = match self.editions.get(Id::U64(token_id)) {
+                Some((Id::U64(master_id), _)) => master_id,
+                _ => token_id,
+            };
             let value = self.get_attribute(Id::U8(0), String::from("baseUri").into_bytes());
             let mut token_uri = String::from_utf8(value.unwrap()).unwrap();
-            token_uri = token_uri + &token_id.to_string() + &String::from(".json");
+            token_uri = token_uri + &metadata_id.to_string() + &String::from(".json");
             Ok(token_uri)
         }
 
@@ -184,12 +359,269 @@ pub mod shiden34 {
         fn max_supply(&self) -> u64 {
             self.max_supply
         }
+
+        /// Set the royalty fee and the creators who share in it
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn set_royalties(
+            &mut self,
+            fee_bps: u16,
+            creators: Vec<(AccountId, u16)>,
+        ) -> Result<(), PSP34Error> {
+            Self::check_royalties(fee_bps, &creators)?;
+            self.seller_fee_basis_points = fee_bps;
+            self.creators = creators;
+            Ok(())
+        }
+
+        /// Get the royalty split for a sale at the given price
+        #[ink(message)]
+        fn royalty_info(
+            &self,
+            _token_id: u64,
+            sale_price: Balance,
+        ) -> Result<Vec<(AccountId, Balance)>, PSP34Error> {
+            let total_royalty = sale_price
+                .checked_mul(self.seller_fee_basis_points as Balance)
+                .map(|product| product / 10_000)
+                .ok_or_else(|| PSP34Error::Custom("RoyaltyOverflow".to_string()))?;
+
+            self.creators
+                .iter()
+                .map(|(account, share)| {
+                    total_royalty
+                        .checked_mul(*share as Balance)
+                        .map(|product| (*account, product / 100))
+                        .ok_or_else(|| PSP34Error::Custom("RoyaltyOverflow".to_string()))
+                })
+                .collect()
+        }
+
+        /// Give a token a finite number of uses
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn set_uses(&mut self, token_id: u64, method: UseMethod, total: u64) -> Result<(), PSP34Error> {
+            self.token_exists(Id::U64(token_id))?;
+            self.uses.insert(Id::U64(token_id), &(method, total, total));
+            Ok(())
+        }
+
+        /// Consume one use of a token, burning it once a `Burn`-method token runs out
+        #[ink(message)]
+        fn use_token(&mut self, token_id: u64) -> Result<(), PSP34Error> {
+            let id = Id::U64(token_id);
+            let caller = self.env().caller();
+            let owner = self.ensure_owner_or_approved(id.clone(), caller)?;
+
+            let (method, total, remaining) = self
+                .uses
+                .get(&id)
+                .ok_or_else(|| PSP34Error::Custom("NoUsesSet".to_string()))?;
+            if remaining == 0 {
+                return Err(PSP34Error::Custom("NoMoreUses".to_string()))
+            }
+
+            let remaining = remaining - 1;
+            if method == UseMethod::Burn && remaining == 0 {
+                self.uses.remove(&id);
+                self._burn_from(owner, id)?;
+            } else {
+                self.uses.insert(id, &(method, total, remaining));
+            }
+
+            Ok(())
+        }
+
+        /// Get the use method, total uses and remaining uses of a token
+        #[ink(message)]
+        fn uses_of(&self, token_id: u64) -> Option<(UseMethod, u64, u64)> {
+            self.uses.get(Id::U64(token_id))
+        }
+
+        /// Set the account authorized to verify tokens as members of this collection
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn set_collection_authority(&mut self, authority: AccountId) -> Result<(), PSP34Error> {
+            self.collection_authority = Some(authority);
+            Ok(())
+        }
+
+        /// Mark a token as a verified member of the collection
+        #[ink(message)]
+        fn verify_collection(&mut self, token_id: u64) -> Result<(), PSP34Error> {
+            self.ensure_collection_authority()?;
+            let id = Id::U64(token_id);
+            self.token_exists(id.clone())?;
+            self.verified.insert(&id, &true);
+            self.env().emit_event(CollectionVerified { token_id: id });
+            Ok(())
+        }
+
+        /// Remove a token's verified membership in the collection
+        #[ink(message)]
+        fn unverify_collection(&mut self, token_id: u64) -> Result<(), PSP34Error> {
+            self.ensure_collection_authority()?;
+            let id = Id::U64(token_id);
+            self.token_exists(id.clone())?;
+            self.verified.remove(&id);
+            self.env().emit_event(CollectionUnverified { token_id: id });
+            Ok(())
+        }
+
+        /// Check whether a token is a verified member of the collection
+        #[ink(message)]
+        fn is_verified(&self, token_id: u64) -> bool {
+            self.verified.get(Id::U64(token_id)).unwrap_or(false)
+        }
+
+        /// Turn an existing token into a master edition that can print numbered copies
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn create_master_edition(&mut self, token_id: u64, max_editions: u64) -> Result<(), PSP34Error> {
+            let id = Id::U64(token_id);
+            self.token_exists(id.clone())?;
+            self.masters.insert(&id, &max_editions);
+            Ok(())
+        }
+
+        /// Mint the next numbered print from a master edition
+        #[ink(message, payable)]
+        fn mint_edition_from_master(&mut self, master_id: u64) -> Result<(), PSP34Error> {
+            self.check_value(1)?;
+            let master = Id::U64(master_id);
+            let max_editions = self
+                .masters
+                .get(&master)
+                .ok_or_else(|| PSP34Error::Custom("MasterNotFound".to_string()))?;
+
+            let edition_number = self.edition_counters.get(&master).unwrap_or(0) + 1;
+            if edition_number > max_editions {
+                return Err(PSP34Error::Custom("MaxEditionsReached".to_string()))
+            }
+
+            let caller = self.env().caller();
+            self.last_edition_id += 1;
+            let print_id = Id::U64(EDITION_ID_OFFSET + self.last_edition_id);
+            self._mint_to(caller, print_id.clone())?;
+            self.edition_counters.insert(&master, &edition_number);
+            self.editions.insert(&print_id, &(master, edition_number));
+
+            Ok(())
+        }
+
+        /// Get the `(master_id, edition_number)` of a print, if it is one
+        #[ink(message)]
+        fn edition_of(&self, token_id: u64) -> Option<(u64, u64)> {
+            self.editions
+                .get(Id::U64(token_id))
+                .and_then(|(master, number)| match master {
+                    Id::U64(master_id) => Some((master_id, number)),
+                    _ => None,
+                })
+        }
+
+        /// Withdraw the contract's balance (minus the existential deposit) to the owner
+        #[ink(message)]
+        #[modifiers(only_owner, non_reentrant)]
+        fn withdraw(&mut self) -> Result<(), PSP34Error> {
+            let balance = self.env().balance();
+            let reserve = self.env().minimum_balance();
+            let value = balance.saturating_sub(reserve);
+            let owner = self.owner();
+            self.env()
+                .transfer(owner, value)
+                .map_err(|_| PSP34Error::Custom("WithdrawalFailed".to_string()))
+        }
+
+        /// Set the price charged per minted token
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn set_price_per_mint(&mut self, price: Balance) -> Result<(), PSP34Error> {
+            self.price_per_mint = price;
+            Ok(())
+        }
+
+        /// Set the maximum number of tokens of the base collection; edition prints are not counted
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn set_max_supply(&mut self, max_supply: u64) -> Result<(), PSP34Error> {
+            if max_supply < self.last_token_id {
+                return Err(PSP34Error::Custom("CannotOrphanMintedTokens".to_string()))
+            }
+            self.max_supply = max_supply;
+            Ok(())
+        }
+
+        /// Set the root of the presale allowlist merkle tree
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn set_merkle_root(&mut self, root: [u8; 32]) -> Result<(), PSP34Error> {
+            self.merkle_root = root;
+            Ok(())
+        }
+
+        /// Turn the presale phase on or off
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn set_presale_active(&mut self, active: bool) -> Result<(), PSP34Error> {
+            self.presale_active = active;
+            Ok(())
+        }
+
+        /// Set the discounted price charged during the presale
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn set_presale_price(&mut self, price: Option<Balance>) -> Result<(), PSP34Error> {
+            self.presale_price = price;
+            Ok(())
+        }
+
+        /// Mint tokens during the presale to a caller proven to be on the allowlist
+        #[ink(message, payable)]
+        fn mint_presale(
+            &mut self,
+            proof: Vec<[u8; 32]>,
+            max_allowed: u64,
+            mint_amount: u64,
+        ) -> Result<(), PSP34Error> {
+            if !self.presale_active {
+                return Err(PSP34Error::Custom("PresaleNotActive".to_string()))
+            }
+
+            let caller = self.env().caller();
+            if !self.verify_presale_proof(caller, max_allowed, proof) {
+                return Err(PSP34Error::Custom("NotInAllowlist".to_string()))
+            }
+
+            let already_minted = self.presale_mints.get(&caller).unwrap_or(0);
+            if already_minted + mint_amount > max_allowed {
+                return Err(PSP34Error::Custom("PresaleAllowanceExceeded".to_string()))
+            }
+
+            self.check_amount(mint_amount)?;
+            let price = self.presale_price.unwrap_or(self.price_per_mint);
+            if Self::env().transferred_value() != mint_amount as u128 * price {
+                return Err(PSP34Error::Custom("BadMintValue".to_string()))
+            }
+
+            let next_to_mint = self.last_token_id + 1;
+            let mint_offset = next_to_mint + mint_amount;
+            for mint_id in next_to_mint..mint_offset {
+                assert!(self._mint_to(caller, Id::U64(mint_id)).is_ok());
+                self.last_token_id += 1;
+            }
+
+            self.presale_mints.insert(&caller, &(already_minted + mint_amount));
+
+            Ok(())
+        }
     }
 
     #[cfg(test)]
     mod tests {
         use super::*;
         use ink_lang as ink;
+        use ink_prelude::vec;
         const PRICE: Balance = 100_000_000_000_000_000;
         const BASE_URI: &str = "ipfs://myIpfsUri/";
         const MAX_SUPPLY: u64 = 10;
@@ -222,6 +654,9 @@ pub mod shiden34 {
                 String::from(BASE_URI),
                 MAX_SUPPLY,
                 PRICE,
+                0,
+                Vec::new(),
+                None,
             )
         }
 
@@ -345,6 +780,257 @@ pub mod shiden34 {
             );
         }
 
+        #[ink::test]
+        fn set_royalties_and_royalty_info_works() {
+            let accounts = default_accounts();
+            let mut sh34 = init();
+
+            set_sender(accounts.alice);
+            assert!(sh34
+                .set_royalties(500, vec![(accounts.alice, 70), (accounts.bob, 30)])
+                .is_ok());
+            assert_eq!(
+                sh34.royalty_info(1, 1_000_000),
+                Ok(vec![(accounts.alice, 35_000), (accounts.bob, 15_000)])
+            );
+
+            set_sender(accounts.bob);
+            assert_eq!(
+                sh34.set_royalties(500, vec![(accounts.bob, 100)]),
+                Err(Custom("O::CallerIsNotOwner".to_string()))
+            );
+        }
+
+        #[ink::test]
+        fn royalty_info_rejects_overflowing_sale_price() {
+            let accounts = default_accounts();
+            let mut sh34 = init();
+            set_sender(accounts.alice);
+
+            assert!(sh34.set_royalties(500, vec![(accounts.alice, 100)]).is_ok());
+            assert_eq!(
+                sh34.royalty_info(1, Balance::MAX),
+                Err(Custom("RoyaltyOverflow".to_string()))
+            );
+        }
+
+        #[ink::test]
+        fn set_royalties_rejects_bad_config() {
+            let accounts = default_accounts();
+            let mut sh34 = init();
+            set_sender(accounts.alice);
+
+            assert_eq!(
+                sh34.set_royalties(10_001, vec![(accounts.alice, 100)]),
+                Err(Custom("InvalidFeeBasisPoints".to_string()))
+            );
+            assert_eq!(
+                sh34.set_royalties(500, vec![(accounts.alice, 60)]),
+                Err(Custom("InvalidCreatorShares".to_string()))
+            );
+            assert_eq!(
+                sh34.set_royalties(
+                    500,
+                    vec![
+                        (accounts.alice, 20),
+                        (accounts.bob, 20),
+                        (accounts.charlie, 20),
+                        (accounts.django, 20),
+                        (accounts.eve, 10),
+                        (accounts.frank, 10),
+                    ]
+                ),
+                Err(Custom("TooManyCreators".to_string()))
+            );
+        }
+
+        #[ink::test]
+        fn use_token_burns_after_last_use() {
+            let accounts = default_accounts();
+            let mut sh34 = init();
+            set_sender(accounts.alice);
+            test::set_value_transferred::<ink_env::DefaultEnvironment>(PRICE);
+            assert!(sh34.mint(accounts.alice, Id::U64(0)).is_ok());
+
+            assert!(sh34.set_uses(1, UseMethod::Burn, 2).is_ok());
+            assert_eq!(sh34.uses_of(1), Some((UseMethod::Burn, 2, 2)));
+
+            assert!(sh34.use_token(1).is_ok());
+            assert_eq!(sh34.uses_of(1), Some((UseMethod::Burn, 2, 1)));
+            assert_eq!(sh34.owner_of(Id::U64(1)), Some(accounts.alice));
+
+            assert!(sh34.use_token(1).is_ok());
+            assert_eq!(sh34.owner_of(Id::U64(1)), None);
+            assert_eq!(sh34.uses_of(1), None);
+        }
+
+        #[ink::test]
+        fn use_token_fails_when_exhausted_or_unauthorized() {
+            let accounts = default_accounts();
+            let mut sh34 = init();
+            set_sender(accounts.alice);
+            test::set_value_transferred::<ink_env::DefaultEnvironment>(PRICE);
+            assert!(sh34.mint(accounts.alice, Id::U64(0)).is_ok());
+            assert!(sh34.set_uses(1, UseMethod::Single, 1).is_ok());
+
+            set_sender(accounts.bob);
+            assert_eq!(sh34.use_token(1), Err(NotApproved));
+
+            set_sender(accounts.alice);
+            assert!(sh34.use_token(1).is_ok());
+            assert_eq!(sh34.use_token(1), Err(Custom("NoMoreUses".to_string())));
+        }
+
+        #[ink::test]
+        fn verify_collection_works() {
+            let accounts = default_accounts();
+            let mut sh34 = init();
+            set_sender(accounts.alice);
+            test::set_value_transferred::<ink_env::DefaultEnvironment>(PRICE);
+            assert!(sh34.mint(accounts.alice, Id::U64(0)).is_ok());
+
+            assert!(sh34.set_collection_authority(accounts.bob).is_ok());
+            assert_eq!(
+                sh34.verify_collection(1),
+                Err(Custom("NotCollectionAuthority".to_string()))
+            );
+
+            set_sender(accounts.bob);
+            assert!(!sh34.is_verified(1));
+            assert!(sh34.verify_collection(1).is_ok());
+            assert!(sh34.is_verified(1));
+            assert_eq!(sh34.verify_collection(42), Err(TokenNotExists));
+
+            assert!(sh34.unverify_collection(1).is_ok());
+            assert!(!sh34.is_verified(1));
+        }
+
+        #[ink::test]
+        fn mint_edition_from_master_works() {
+            let accounts = default_accounts();
+            let mut sh34 = init();
+            set_sender(accounts.alice);
+            test::set_value_transferred::<ink_env::DefaultEnvironment>(PRICE);
+            assert!(sh34.mint(accounts.alice, Id::U64(0)).is_ok());
+            assert!(sh34.create_master_edition(1, 2).is_ok());
+
+            let first_print_id = EDITION_ID_OFFSET + 1;
+            let second_print_id = EDITION_ID_OFFSET + 2;
+
+            set_sender(accounts.bob);
+            test::set_value_transferred::<ink_env::DefaultEnvironment>(PRICE);
+            assert!(sh34.mint_edition_from_master(1).is_ok());
+            assert_eq!(sh34.edition_of(first_print_id), Some((1, 1)));
+            assert_eq!(sh34.token_uri(first_print_id), sh34.token_uri(1));
+
+            test::set_value_transferred::<ink_env::DefaultEnvironment>(PRICE);
+            assert!(sh34.mint_edition_from_master(1).is_ok());
+            assert_eq!(sh34.edition_of(second_print_id), Some((1, 2)));
+
+            test::set_value_transferred::<ink_env::DefaultEnvironment>(PRICE);
+            assert_eq!(
+                sh34.mint_edition_from_master(1),
+                Err(Custom("MaxEditionsReached".to_string()))
+            );
+        }
+
+        #[ink::test]
+        fn mint_edition_from_master_does_not_consume_base_supply() {
+            let accounts = default_accounts();
+            let mut sh34 = init();
+            set_sender(accounts.alice);
+            test::set_value_transferred::<ink_env::DefaultEnvironment>(PRICE);
+            assert!(sh34.mint(accounts.alice, Id::U64(0)).is_ok());
+            assert!(sh34.create_master_edition(1, MAX_SUPPLY).is_ok());
+
+            for _ in 0..MAX_SUPPLY {
+                test::set_value_transferred::<ink_env::DefaultEnvironment>(PRICE);
+                assert!(sh34.mint_edition_from_master(1).is_ok());
+            }
+            assert_eq!(sh34.last_token_id, 1);
+
+            test::set_value_transferred::<ink_env::DefaultEnvironment>(
+                PRICE * (MAX_SUPPLY - 1) as u128,
+            );
+            assert!(sh34.mint_for(accounts.bob, MAX_SUPPLY - 1).is_ok());
+        }
+
+        #[ink::test]
+        fn withdraw_works() {
+            let accounts = default_accounts();
+            let mut sh34 = init();
+            set_sender(accounts.alice);
+            test::set_value_transferred::<ink_env::DefaultEnvironment>(PRICE);
+            assert!(sh34.mint(accounts.alice, Id::U64(0)).is_ok());
+
+            set_sender(accounts.bob);
+            assert_eq!(
+                sh34.withdraw(),
+                Err(Custom("O::CallerIsNotOwner".to_string()))
+            );
+
+            set_sender(accounts.alice);
+            assert!(sh34.withdraw().is_ok());
+        }
+
+        #[ink::test]
+        fn set_price_and_max_supply_works() {
+            let accounts = default_accounts();
+            let mut sh34 = init();
+            set_sender(accounts.alice);
+            test::set_value_transferred::<ink_env::DefaultEnvironment>(PRICE);
+            assert!(sh34.mint(accounts.alice, Id::U64(0)).is_ok());
+
+            assert!(sh34.set_price_per_mint(PRICE * 2).is_ok());
+            assert_eq!(sh34.price_per_mint, PRICE * 2);
+
+            assert_eq!(
+                sh34.set_max_supply(0),
+                Err(Custom("CannotOrphanMintedTokens".to_string()))
+            );
+            assert!(sh34.set_max_supply(MAX_SUPPLY * 2).is_ok());
+            assert_eq!(sh34.max_supply(), MAX_SUPPLY * 2);
+        }
+
+        #[ink::test]
+        fn mint_presale_works() {
+            let accounts = default_accounts();
+            let mut sh34 = init();
+            set_sender(accounts.alice);
+
+            let max_allowed: u64 = 3;
+            let mut leaf_input = accounts.alice.encode();
+            leaf_input.extend_from_slice(&max_allowed.to_le_bytes());
+            let root = Shiden34Contract::keccak256(&leaf_input);
+
+            assert!(sh34.set_merkle_root(root).is_ok());
+            assert!(sh34.set_presale_price(Some(PRICE / 2)).is_ok());
+
+            assert_eq!(
+                sh34.mint_presale(Vec::new(), max_allowed, 1),
+                Err(Custom("PresaleNotActive".to_string()))
+            );
+
+            assert!(sh34.set_presale_active(true).is_ok());
+
+            test::set_value_transferred::<ink_env::DefaultEnvironment>(PRICE / 2 * 2);
+            assert!(sh34.mint_presale(Vec::new(), max_allowed, 2).is_ok());
+            assert_eq!(sh34.balance_of(accounts.alice), 2);
+
+            test::set_value_transferred::<ink_env::DefaultEnvironment>(PRICE / 2 * 2);
+            assert_eq!(
+                sh34.mint_presale(Vec::new(), max_allowed, 2),
+                Err(Custom("PresaleAllowanceExceeded".to_string()))
+            );
+
+            set_sender(accounts.bob);
+            test::set_value_transferred::<ink_env::DefaultEnvironment>(PRICE / 2);
+            assert_eq!(
+                sh34.mint_presale(Vec::new(), max_allowed, 1),
+                Err(Custom("NotInAllowlist".to_string()))
+            );
+        }
+
         fn default_accounts() -> test::DefaultAccounts<ink_env::DefaultEnvironment> {
             test::default_accounts::<Environment>()
         }